@@ -1,5 +1,5 @@
-use edr_eth::remote::methods::U64OrUsize;
-use edr_evm::{blockchain::BlockchainError, MineBlockResult};
+use edr_eth::{remote::methods::U64OrUsize, Bytes, U256};
+use edr_evm::{blockchain::BlockchainError, ExecutionResult, MineBlockResult};
 
 use crate::{data::ProviderData, ProviderError};
 
@@ -20,11 +20,86 @@ pub fn handle_mine_request(
     let timestamp: Option<u64> = timestamp.map(U64OrUsize::into);
     let mine_block_result = data.mine_and_commit_block(timestamp)?;
 
-    log_block(&mine_block_result)?;
+    log_block(data, &mine_block_result)?;
 
     Ok(String::from("0"))
 }
 
+/// Parses `hardhat_mine`'s optional `count`/`interval` params, defaulting
+/// each to `1` as Hardhat Network does.
+fn parse_hardhat_mine_params(
+    count: Option<U64OrUsize>,
+    interval: Option<U64OrUsize>,
+) -> (u64, u64) {
+    (
+        count.map_or(1, U64OrUsize::into),
+        interval.map_or(1, U64OrUsize::into),
+    )
+}
+
+/// Mines `count` blocks in a single call, spaced `interval` seconds apart,
+/// and returns the result of every mined block (each fed through
+/// [`log_block`]).
+///
+/// The first block, and every subsequent block for as long as the mempool
+/// still has pending transactions, is mined the normal way so transactions
+/// that overflow a single block's gas limit spill over into later blocks
+/// instead of being stranded. Only once the mempool is confirmed empty do
+/// the remaining blocks in the range become cheap reservations that skip
+/// the EVM transaction loop entirely.
+pub fn handle_hardhat_mine(
+    data: &mut ProviderData,
+    count: Option<U64OrUsize>,
+    interval: Option<U64OrUsize>,
+) -> Result<Vec<MineBlockResult<BlockchainError>>, ProviderError> {
+    let (count, interval) = parse_hardhat_mine_params(count, interval);
+
+    if count == 0 {
+        return Ok(Vec::new());
+    }
+
+    let mut results = Vec::with_capacity(count as usize);
+    let mut remaining = count;
+
+    let first_block_timestamp = data.increase_block_time(interval);
+    let mine_block_result = data.mine_and_commit_block(Some(first_block_timestamp))?;
+    log_block(data, &mine_block_result)?;
+    results.push(mine_block_result);
+    remaining -= 1;
+
+    while remaining > 0 && data.has_pending_transactions() {
+        let block_timestamp = data.increase_block_time(interval);
+        let mine_block_result = data.mine_and_commit_block(Some(block_timestamp))?;
+        log_block(data, &mine_block_result)?;
+        results.push(mine_block_result);
+        remaining -= 1;
+    }
+
+    if remaining > 0 {
+        let reserved_block_results = data.reserve_blocks(remaining, interval)?;
+
+        for mine_block_result in &reserved_block_results {
+            log_block(data, mine_block_result)?;
+        }
+
+        results.extend(reserved_block_results);
+    }
+
+    Ok(results)
+}
+
+/// Returns whether a node with the given automine/interval-mining state is
+/// currently producing blocks.
+fn is_mining(auto_mining: bool, interval_mining_enabled: bool) -> bool {
+    auto_mining || interval_mining_enabled
+}
+
+/// Returns whether the node is currently producing blocks, i.e. whether
+/// automine is enabled or an interval-mining timer is active.
+pub fn handle_mining_request(data: &mut ProviderData) -> Result<bool, ProviderError> {
+    Ok(is_mining(data.is_auto_mining(), data.is_interval_mining_enabled()))
+}
+
 pub fn handle_set_automine_request(
     data: &mut ProviderData,
     automine: bool,
@@ -53,6 +128,178 @@ pub fn handle_set_next_block_timestamp_request(
     Ok(new_timestamp.to_string())
 }
 
-fn log_block(_mine_block_result: &MineBlockResult<BlockchainError>) -> Result<(), ProviderError> {
-    Err(ProviderError::Unimplemented("log_block".to_string()))
+/// The selector of Solidity's `Error(string)`, used to encode revert reasons.
+const SOLIDITY_ERROR_SELECTOR: [u8; 4] = [0x08, 0xc3, 0x79, 0xa0];
+
+/// Attempts to decode a Solidity `Error(string)` revert reason from raw
+/// returndata. Returns `None` if the returndata isn't shaped like one.
+fn decode_revert_reason(output: &Bytes) -> Option<String> {
+    let selector = output.get(..4)?;
+    if selector != SOLIDITY_ERROR_SELECTOR {
+        return None;
+    }
+
+    let encoded = output.get(4..)?;
+    let length: usize = U256::try_from_be_slice(encoded.get(32..64)?)?
+        .try_into()
+        .ok()?;
+
+    // `length` is attacker-controlled (it comes straight from the revert
+    // data), so bound it against the available bytes before adding to avoid
+    // overflowing `64 + length`.
+    if length > encoded.len().checked_sub(64)? {
+        return None;
+    }
+
+    let string_bytes = encoded.get(64..64 + length)?;
+    String::from_utf8(string_bytes.to_vec()).ok()
+}
+
+/// Renders a mined block's summary into the provider's logger: the block
+/// number and hash, base fee, gas used/limit, and a per-transaction summary
+/// of gas used, success/revert (with decoded revert reason when available)
+/// and emitted logs. This mirrors the block summary Hardhat Network prints
+/// on each mined block.
+fn log_block(
+    data: &mut ProviderData,
+    mine_block_result: &MineBlockResult<BlockchainError>,
+) -> Result<(), ProviderError> {
+    let block = &mine_block_result.block;
+    let header = block.header();
+
+    let mut lines = vec![format!("Block #{} mined: {:?}", header.number, block.hash())];
+
+    if let Some(base_fee_per_gas) = header.base_fee_per_gas {
+        lines.push(format!("  Base fee: {base_fee_per_gas}"));
+    }
+
+    lines.push(format!(
+        "  Gas used: {}/{}",
+        header.gas_used, header.gas_limit
+    ));
+
+    for (transaction, result) in block
+        .transactions()
+        .iter()
+        .zip(mine_block_result.transaction_results.iter())
+    {
+        lines.push(format!("  Transaction: {:?}", transaction.hash()));
+        lines.push(format!("    From: {:?}", transaction.caller()));
+
+        match transaction.to() {
+            Some(to) => lines.push(format!("    To:   {to:?}")),
+            None => lines.push(String::from("    To:   <contract creation>")),
+        }
+
+        lines.push(format!("    Gas used: {}", result.gas_used()));
+
+        match result {
+            ExecutionResult::Success { logs, .. } => {
+                lines.push(String::from("    Success"));
+
+                for log in logs {
+                    lines.push(format!("      Log emitted from address {:?}", log.address));
+                }
+            }
+            ExecutionResult::Revert { output, .. } => {
+                let reason = decode_revert_reason(output)
+                    .unwrap_or_else(|| String::from("<no reason given>"));
+                lines.push(format!("    Transaction reverted: {reason}"));
+            }
+            ExecutionResult::Halt { reason, .. } => {
+                lines.push(format!("    Transaction halted: {reason:?}"));
+            }
+        }
+    }
+
+    data.logger_mut()
+        .log_block(lines)
+        .map_err(ProviderError::Logger)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Encodes a Solidity `Error(string)` revert payload for the given
+    /// reason string.
+    fn encode_revert_reason(reason: &str) -> Bytes {
+        let mut encoded = SOLIDITY_ERROR_SELECTOR.to_vec();
+        encoded.extend_from_slice(&[0u8; 31]);
+        encoded.push(0x20); // offset to the string data
+        encoded.extend_from_slice(&U256::from(reason.len()).to_be_bytes::<32>());
+        encoded.extend_from_slice(reason.as_bytes());
+        // pad to a multiple of 32 bytes, as Solidity ABI encoding does
+        let padding = (32 - reason.len() % 32) % 32;
+        encoded.extend(std::iter::repeat(0u8).take(padding));
+
+        Bytes::from(encoded)
+    }
+
+    #[test]
+    fn decode_revert_reason_decodes_valid_payload() {
+        let output = encode_revert_reason("insufficient balance");
+
+        assert_eq!(
+            decode_revert_reason(&output),
+            Some(String::from("insufficient balance"))
+        );
+    }
+
+    #[test]
+    fn decode_revert_reason_rejects_wrong_selector() {
+        let mut output = encode_revert_reason("reason").to_vec();
+        output[0] ^= 0xff;
+
+        assert_eq!(decode_revert_reason(&Bytes::from(output)), None);
+    }
+
+    #[test]
+    fn decode_revert_reason_does_not_overflow_on_forged_huge_length() {
+        let mut encoded = SOLIDITY_ERROR_SELECTOR.to_vec();
+        encoded.extend_from_slice(&[0u8; 31]);
+        encoded.push(0x20);
+        // A length word of u64::MAX would overflow `64 + length` on a
+        // 64-bit target if added unchecked.
+        encoded.extend_from_slice(&U256::from(u64::MAX).to_be_bytes::<32>());
+
+        assert_eq!(decode_revert_reason(&Bytes::from(encoded)), None);
+    }
+
+    #[test]
+    fn decode_revert_reason_rejects_truncated_payload() {
+        let mut output = encode_revert_reason("a longer reason than the data").to_vec();
+        output.truncate(output.len() - 10);
+
+        assert_eq!(decode_revert_reason(&Bytes::from(output)), None);
+    }
+
+    #[test]
+    fn parse_hardhat_mine_params_defaults_to_one_block_one_second_interval() {
+        assert_eq!(parse_hardhat_mine_params(None, None), (1, 1));
+    }
+
+    #[test]
+    fn parse_hardhat_mine_params_reports_a_zero_count() {
+        assert_eq!(
+            parse_hardhat_mine_params(Some(U64OrUsize::from(0u64)), None),
+            (0, 1)
+        );
+    }
+
+    #[test]
+    fn parse_hardhat_mine_params_passes_through_explicit_values() {
+        assert_eq!(
+            parse_hardhat_mine_params(Some(U64OrUsize::from(5u64)), Some(U64OrUsize::from(30u64))),
+            (5, 30)
+        );
+    }
+
+    #[test]
+    fn is_mining_truth_table() {
+        assert!(!is_mining(false, false));
+        assert!(is_mining(true, false));
+        assert!(is_mining(false, true));
+        assert!(is_mining(true, true));
+    }
 }
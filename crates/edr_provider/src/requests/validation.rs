@@ -1,16 +1,23 @@
 use core::fmt::Debug;
+use std::collections::HashSet;
 
+use c_kzg::{Blob as KzgBlob, Bytes48 as KzgBytes48, KzgProof, KzgSettings};
 use edr_eth::{
     access_list::AccessListItem,
     remote::{eth::CallRequest, BlockSpec, BlockTag, PreEip1898BlockSpec},
     transaction::{EthTransactionRequest, SignedTransaction},
-    Address, SpecId, B256, U256,
+    Address, SpecId, B256, KECCAK_EMPTY, U256,
 };
-use edr_evm::Bytes;
+use edr_evm::{
+    state::{StateError, SyncState},
+    Bytes,
+};
+use sha2::{Digest, Sha256};
 
 use crate::ProviderError;
 
 /// Data used for validating a transaction complies with a [`SpecId`].
+#[derive(Clone, Copy)]
 pub struct SpecValidationData<'data> {
     pub gas_price: Option<&'data U256>,
     pub max_fee_per_gas: Option<&'data U256>,
@@ -18,6 +25,9 @@ pub struct SpecValidationData<'data> {
     pub access_list: Option<&'data Vec<AccessListItem>>,
     pub blobs: Option<&'data Vec<Bytes>>,
     pub blob_hashes: Option<&'data Vec<B256>>,
+    pub max_fee_per_blob_gas: Option<&'data U256>,
+    pub blob_commitments: Option<&'data Vec<Bytes>>,
+    pub blob_proofs: Option<&'data Vec<Bytes>>,
 }
 
 impl<'data> From<&'data EthTransactionRequest> for SpecValidationData<'data> {
@@ -29,6 +39,9 @@ impl<'data> From<&'data EthTransactionRequest> for SpecValidationData<'data> {
             access_list: value.access_list.as_ref(),
             blobs: value.blobs.as_ref(),
             blob_hashes: value.blob_hashes.as_ref(),
+            max_fee_per_blob_gas: value.max_fee_per_blob_gas.as_ref(),
+            blob_commitments: value.blob_commitments.as_ref(),
+            blob_proofs: value.blob_proofs.as_ref(),
         }
     }
 }
@@ -42,6 +55,9 @@ impl<'data> From<&'data CallRequest> for SpecValidationData<'data> {
             access_list: value.access_list.as_ref(),
             blobs: value.blobs.as_ref(),
             blob_hashes: value.blob_hashes.as_ref(),
+            max_fee_per_blob_gas: value.max_fee_per_blob_gas.as_ref(),
+            blob_commitments: value.blob_commitments.as_ref(),
+            blob_proofs: value.blob_proofs.as_ref(),
         }
     }
 }
@@ -56,6 +72,9 @@ impl<'data> From<&'data SignedTransaction> for SpecValidationData<'data> {
                 access_list: None,
                 blobs: None,
                 blob_hashes: None,
+                max_fee_per_blob_gas: None,
+                blob_commitments: None,
+                blob_proofs: None,
             },
             SignedTransaction::PostEip155Legacy(tx) => Self {
                 gas_price: Some(&tx.gas_price),
@@ -64,6 +83,9 @@ impl<'data> From<&'data SignedTransaction> for SpecValidationData<'data> {
                 access_list: None,
                 blobs: None,
                 blob_hashes: None,
+                max_fee_per_blob_gas: None,
+                blob_commitments: None,
+                blob_proofs: None,
             },
             SignedTransaction::Eip2930(tx) => Self {
                 gas_price: Some(&tx.gas_price),
@@ -72,6 +94,9 @@ impl<'data> From<&'data SignedTransaction> for SpecValidationData<'data> {
                 access_list: Some(tx.access_list.0.as_ref()),
                 blobs: None,
                 blob_hashes: None,
+                max_fee_per_blob_gas: None,
+                blob_commitments: None,
+                blob_proofs: None,
             },
             SignedTransaction::Eip1559(tx) => Self {
                 gas_price: None,
@@ -80,6 +105,9 @@ impl<'data> From<&'data SignedTransaction> for SpecValidationData<'data> {
                 access_list: Some(tx.access_list.0.as_ref()),
                 blobs: None,
                 blob_hashes: None,
+                max_fee_per_blob_gas: None,
+                blob_commitments: None,
+                blob_proofs: None,
             },
             SignedTransaction::Eip4844(tx) => Self {
                 gas_price: None,
@@ -88,11 +116,39 @@ impl<'data> From<&'data SignedTransaction> for SpecValidationData<'data> {
                 access_list: Some(tx.access_list.0.as_ref()),
                 blobs: None,
                 blob_hashes: Some(tx.blob_hashes.as_ref()),
+                max_fee_per_blob_gas: Some(&tx.max_fee_per_blob_gas),
+                // The blob sidecar (raw blobs, KZG commitments and proofs) is
+                // only carried on the network wrapper used at submission
+                // time; it is not part of the signed transaction envelope.
+                blob_commitments: None,
+                blob_proofs: None,
             },
         }
     }
 }
 
+/// Builds [`SpecValidationData`] for a signed EIP-4844 transaction together
+/// with the blob sidecar (blobs, KZG commitments and proofs) carried by the
+/// network wrapper used at submission time, since the signed transaction
+/// envelope alone never carries it (see the `Eip4844` arm of
+/// `impl From<&SignedTransaction> for SpecValidationData`). Callers that
+/// decode a pooled/network EIP-4844 transaction must use this instead of
+/// the bare `SignedTransaction` conversion, or blob sidecar validation is
+/// silently skipped.
+pub fn eip4844_validation_data_with_sidecar<'data>(
+    transaction: &'data SignedTransaction,
+    blobs: &'data Vec<Bytes>,
+    blob_commitments: &'data Vec<Bytes>,
+    blob_proofs: &'data Vec<Bytes>,
+) -> SpecValidationData<'data> {
+    let mut data: SpecValidationData<'data> = transaction.into();
+    data.blobs = Some(blobs);
+    data.blob_commitments = Some(blob_commitments);
+    data.blob_proofs = Some(blob_proofs);
+
+    data
+}
+
 fn validate_transaction_spec<LoggerErrorT: Debug>(
     spec_id: SpecId,
     data: SpecValidationData<'_>,
@@ -104,6 +160,9 @@ fn validate_transaction_spec<LoggerErrorT: Debug>(
         access_list,
         blobs,
         blob_hashes,
+        max_fee_per_blob_gas: _,
+        blob_commitments: _,
+        blob_proofs: _,
     } = data;
 
     if spec_id < SpecId::BERLIN && access_list.is_some() {
@@ -167,16 +226,44 @@ fn validate_transaction_spec<LoggerErrorT: Debug>(
     Ok(())
 }
 
+/// Chain and state context needed to fully validate a transaction or call
+/// beyond its own fields: the account state (for EIP-3607), the fee market
+/// parameters of the block it would be mined in, and (from
+/// [bloodybit/hardhat#chunk0-3] onwards) the blob gas market and KZG trusted
+/// setup used to validate blob sidecars.
+pub struct TransactionValidationContext<'data> {
+    pub state: &'data dyn SyncState<StateError>,
+    pub impersonated_accounts: &'data HashSet<Address>,
+    /// The base fee of the block the transaction would be mined in, if the
+    /// active hardfork supports EIP-1559. `None` suppresses the
+    /// `maxFeePerGas` check, e.g. when the pending block's base fee can't
+    /// yet be determined.
+    pub next_block_base_fee: Option<U256>,
+    /// When set, `maxFeePerGas` is not required to cover
+    /// `next_block_base_fee`. Used by `eth_call`/`eth_estimateGas`, where an
+    /// under-priced fee shouldn't block simulation.
+    pub allow_unpriced_transactions: bool,
+    /// The excess blob gas of the parent block, used to derive the next
+    /// block's blob base fee. `None` suppresses the `maxFeePerBlobGas`
+    /// check.
+    pub parent_excess_blob_gas: Option<u64>,
+    pub kzg_settings: &'data KzgSettings,
+}
+
 pub fn validate_call_request<LoggerErrorT: Debug>(
     spec_id: SpecId,
     call_request: &CallRequest,
     block_spec: &BlockSpec,
-) -> Result<(), ProviderError<LoggerErrorT>> {
+    context: TransactionValidationContext<'_>,
+) -> Result<U256, ProviderError<LoggerErrorT>> {
     validate_post_merge_block_tags(spec_id, block_spec)?;
 
     validate_transaction_and_call_request(
         spec_id,
         <&CallRequest as Into<SpecValidationData<'_>>>::into(call_request),
+        call_request.from.unwrap_or_default(),
+        call_request.to.as_ref(),
+        context,
     ).map_err(|err| match err {
         ProviderError::UnsupportedEIP1559Parameters {
             minimum_hardfork, ..
@@ -189,22 +276,376 @@ You can use them by running Hardhat Network with 'hardfork' {minimum_hardfork:?}
     })
 }
 
+/// Validates a transaction or call against the active [`SpecId`], including
+/// that its sender is allowed to originate it per EIP-3607 (see
+/// [`validate_sender_has_no_code`]), that its `maxFeePerGas` can pay the
+/// next block's base fee (see [`validate_max_fee_per_gas`]), and that its
+/// blob sidecar, if any, is well-formed (see [`validate_blob_transaction`]).
+/// Returns the transaction's effective gas price (see
+/// [`effective_gas_price`]).
 pub fn validate_transaction_and_call_request<'a, LoggerErrorT: Debug>(
     spec_id: SpecId,
     validation_data: impl Into<SpecValidationData<'a>>,
-) -> Result<(), ProviderError<LoggerErrorT>> {
-    validate_transaction_spec(spec_id, validation_data.into()).map_err(|err| match err {
+    from: Address,
+    to: Option<&Address>,
+    context: TransactionValidationContext<'a>,
+) -> Result<U256, ProviderError<LoggerErrorT>> {
+    let validation_data: SpecValidationData<'a> = validation_data.into();
+
+    validate_transaction_spec(spec_id, validation_data).map_err(|err| match err {
         ProviderError::UnsupportedAccessListParameter {
             minimum_hardfork, ..
         } => ProviderError::InvalidArgument(format!(
             "\
-Access list received but is not supported by the current hardfork. 
+Access list received but is not supported by the current hardfork.
 
 You can use them by running Hardhat Network with 'hardfork' {minimum_hardfork:?} or later.
         "
         )),
         err => err,
-    })
+    })?;
+
+    validate_sender_has_no_code(
+        spec_id,
+        context.state,
+        &from,
+        context.impersonated_accounts,
+    )?;
+
+    if let (Some(&max_fee_per_gas), Some(next_block_base_fee)) =
+        (validation_data.max_fee_per_gas, context.next_block_base_fee)
+    {
+        validate_max_fee_per_gas(
+            spec_id,
+            max_fee_per_gas,
+            next_block_base_fee,
+            context.allow_unpriced_transactions,
+        )?;
+    }
+
+    if let Some(blobs) = validation_data.blobs {
+        let no_blob_hashes = Vec::new();
+        let blob_hashes = validation_data.blob_hashes.unwrap_or(&no_blob_hashes);
+        let no_blobs = Vec::new();
+        let blob_commitments = validation_data.blob_commitments.unwrap_or(&no_blobs);
+        let blob_proofs = validation_data.blob_proofs.unwrap_or(&no_blobs);
+
+        validate_blob_transaction(
+            spec_id,
+            to,
+            blobs,
+            blob_hashes,
+            blob_commitments,
+            blob_proofs,
+            validation_data.max_fee_per_blob_gas,
+            context.parent_excess_blob_gas,
+            context.kzg_settings,
+        )?;
+    }
+
+    Ok(effective_gas_price(
+        validation_data.gas_price.copied(),
+        validation_data.max_fee_per_gas.copied(),
+        validation_data.max_priority_fee_per_gas.copied(),
+        context.next_block_base_fee,
+    ))
+}
+
+/// The denominator used by [EIP-1559](https://eips.ethereum.org/EIPS/eip-1559)
+/// to bound how much the base fee can change between two consecutive blocks.
+const BASE_FEE_MAX_CHANGE_DENOMINATOR: u64 = 8;
+
+/// The divisor applied to a block's gas limit to obtain its long-run target
+/// gas usage, per [EIP-1559](https://eips.ethereum.org/EIPS/eip-1559).
+const ELASTICITY_MULTIPLIER: u64 = 2;
+
+/// Computes the base fee of the block that follows a block with the
+/// provided gas usage, gas limit and base fee, per the formula defined in
+/// [EIP-1559](https://eips.ethereum.org/EIPS/eip-1559).
+pub fn calculate_next_base_fee(parent_gas_used: u64, parent_gas_limit: u64, parent_base_fee: U256) -> U256 {
+    let gas_target = parent_gas_limit / ELASTICITY_MULTIPLIER;
+
+    match parent_gas_used.cmp(&gas_target) {
+        std::cmp::Ordering::Equal => parent_base_fee,
+        std::cmp::Ordering::Greater => {
+            let gas_used_delta = U256::from(parent_gas_used - gas_target);
+            let base_fee_delta = (parent_base_fee * gas_used_delta / U256::from(gas_target))
+                / U256::from(BASE_FEE_MAX_CHANGE_DENOMINATOR);
+
+            parent_base_fee + base_fee_delta.max(U256::from(1))
+        }
+        std::cmp::Ordering::Less => {
+            let gas_delta = U256::from(gas_target - parent_gas_used);
+            let base_fee_delta = (parent_base_fee * gas_delta / U256::from(gas_target))
+                / U256::from(BASE_FEE_MAX_CHANGE_DENOMINATOR);
+
+            parent_base_fee.saturating_sub(base_fee_delta)
+        }
+    }
+}
+
+/// Validates that a transaction's `maxFeePerGas` is high enough to pay the
+/// base fee of the block it would be mined in, so that it isn't accepted
+/// only to stall in the mempool. Skipped when `allow_unpriced_transactions`
+/// is set, e.g. for `eth_call`/`eth_estimateGas`.
+pub fn validate_max_fee_per_gas<LoggerErrorT: Debug>(
+    spec_id: SpecId,
+    max_fee_per_gas: U256,
+    next_block_base_fee: U256,
+    allow_unpriced_transactions: bool,
+) -> Result<(), ProviderError<LoggerErrorT>> {
+    if spec_id >= SpecId::LONDON
+        && !allow_unpriced_transactions
+        && max_fee_per_gas < next_block_base_fee
+    {
+        return Err(ProviderError::MaxFeePerGasTooLow {
+            max_fee_per_gas,
+            block_base_fee: next_block_base_fee,
+        });
+    }
+
+    Ok(())
+}
+
+/// Computes the effective gas price paid by a transaction: `gasPrice` for
+/// legacy transactions, or `min(maxFeePerGas, blockBaseFee +
+/// maxPriorityFeePerGas)` for EIP-1559 transactions, so that both kinds of
+/// transaction are charged consistently against the same block.
+///
+/// `block_base_fee` is `None` when the next block's base fee can't yet be
+/// determined; in that case `maxFeePerGas` is returned as the safe upper
+/// bound the sender is willing to pay, rather than assuming a base fee of
+/// zero (which would understate the price down to just the priority fee).
+pub fn effective_gas_price(
+    gas_price: Option<U256>,
+    max_fee_per_gas: Option<U256>,
+    max_priority_fee_per_gas: Option<U256>,
+    block_base_fee: Option<U256>,
+) -> U256 {
+    if let Some(gas_price) = gas_price {
+        return gas_price;
+    }
+
+    let Some(block_base_fee) = block_base_fee else {
+        return max_fee_per_gas.unwrap_or(U256::ZERO);
+    };
+
+    let max_fee_per_gas = max_fee_per_gas.unwrap_or(block_base_fee);
+    let max_priority_fee_per_gas = max_priority_fee_per_gas.unwrap_or(U256::ZERO);
+
+    max_fee_per_gas.min(block_base_fee + max_priority_fee_per_gas)
+}
+
+/// The byte that marks a versioned hash as having been derived from a KZG
+/// commitment, per [EIP-4844](https://eips.ethereum.org/EIPS/eip-4844).
+const VERSIONED_HASH_VERSION_KZG: u8 = 0x01;
+
+/// The amount of blob gas consumed by a single blob, per
+/// [EIP-4844](https://eips.ethereum.org/EIPS/eip-4844).
+const GAS_PER_BLOB: u64 = 1 << 17;
+
+/// The maximum amount of blob gas that may be consumed by a single block,
+/// per [EIP-4844](https://eips.ethereum.org/EIPS/eip-4844).
+const MAX_BLOB_GAS_PER_BLOCK: u64 = 6 * GAS_PER_BLOB;
+
+/// The maximum number of blobs a single transaction may carry. A
+/// transaction cannot exceed the per-block blob gas limit on its own.
+const MAX_BLOBS_PER_TRANSACTION: usize = (MAX_BLOB_GAS_PER_BLOCK / GAS_PER_BLOB) as usize;
+
+/// Computes the versioned hash of a KZG commitment, per
+/// [EIP-4844](https://eips.ethereum.org/EIPS/eip-4844).
+fn kzg_commitment_to_versioned_hash(commitment: &[u8]) -> B256 {
+    let mut hash: [u8; 32] = Sha256::digest(commitment).into();
+    hash[0] = VERSIONED_HASH_VERSION_KZG;
+
+    B256::from(hash)
+}
+
+/// The minimum blob base fee, in wei, per
+/// [EIP-4844](https://eips.ethereum.org/EIPS/eip-4844).
+const MIN_BLOB_GASPRICE: u64 = 1;
+
+/// The denominator that controls how quickly the blob base fee reacts to
+/// excess blob gas, per [EIP-4844](https://eips.ethereum.org/EIPS/eip-4844).
+const BLOB_GASPRICE_UPDATE_FRACTION: u64 = 3_338_477;
+
+/// Approximates `factor * e^(numerator / denominator)` using the Taylor
+/// expansion that [EIP-4844](https://eips.ethereum.org/EIPS/eip-4844) calls
+/// `fake_exponential`, used to derive the blob base fee from excess blob gas.
+fn fake_exponential(factor: u64, numerator: u64, denominator: u64) -> U256 {
+    let factor = U256::from(factor);
+    let numerator = U256::from(numerator);
+    let denominator = U256::from(denominator);
+
+    let mut i = U256::from(1u64);
+    let mut output = U256::ZERO;
+    let mut numerator_accum = factor * denominator;
+
+    while numerator_accum > U256::ZERO {
+        output += numerator_accum;
+        numerator_accum = numerator_accum * numerator / (denominator * i);
+        i += U256::from(1u64);
+    }
+
+    output / denominator
+}
+
+/// Computes the blob base fee of the block that follows a block with the
+/// given excess blob gas, per
+/// [EIP-4844](https://eips.ethereum.org/EIPS/eip-4844).
+pub fn calculate_blob_gas_price(excess_blob_gas: u64) -> U256 {
+    fake_exponential(
+        MIN_BLOB_GASPRICE,
+        excess_blob_gas,
+        BLOB_GASPRICE_UPDATE_FRACTION,
+    )
+}
+
+/// Validates a blob transaction's sidecar (blobs, versioned hashes and KZG
+/// commitments/proofs), per
+/// [EIP-4844](https://eips.ethereum.org/EIPS/eip-4844).
+#[allow(clippy::too_many_arguments)]
+pub fn validate_blob_transaction<LoggerErrorT: Debug>(
+    spec_id: SpecId,
+    to: Option<&Address>,
+    blobs: &[Bytes],
+    blob_hashes: &[B256],
+    blob_commitments: &[Bytes],
+    blob_proofs: &[Bytes],
+    max_fee_per_blob_gas: Option<&U256>,
+    parent_excess_blob_gas: Option<u64>,
+    kzg_settings: &KzgSettings,
+) -> Result<(), ProviderError<LoggerErrorT>> {
+    if spec_id < SpecId::CANCUN {
+        return Ok(());
+    }
+
+    if to.is_none() {
+        return Err(ProviderError::BlobTransactionMissingTo);
+    }
+
+    if blobs.is_empty() {
+        return Err(ProviderError::EmptyBlobs);
+    }
+
+    if blobs.len() > MAX_BLOBS_PER_TRANSACTION {
+        return Err(ProviderError::TooManyBlobs {
+            actual: blobs.len(),
+            max: MAX_BLOBS_PER_TRANSACTION,
+        });
+    }
+
+    if blob_hashes.len() != blobs.len()
+        || blob_commitments.len() != blobs.len()
+        || blob_proofs.len() != blobs.len()
+    {
+        return Err(ProviderError::BlobSidecarLengthMismatch {
+            blobs: blobs.len(),
+            blob_hashes: blob_hashes.len(),
+            blob_commitments: blob_commitments.len(),
+            blob_proofs: blob_proofs.len(),
+        });
+    }
+
+    let Some(max_fee_per_blob_gas) = max_fee_per_blob_gas else {
+        return Err(ProviderError::MissingMaxFeePerBlobGas);
+    };
+
+    if let Some(parent_excess_blob_gas) = parent_excess_blob_gas {
+        let block_base_fee_per_blob_gas = calculate_blob_gas_price(parent_excess_blob_gas);
+
+        if *max_fee_per_blob_gas < block_base_fee_per_blob_gas {
+            return Err(ProviderError::MaxFeePerBlobGasTooLow {
+                max_fee_per_blob_gas: *max_fee_per_blob_gas,
+                block_base_fee_per_blob_gas,
+            });
+        }
+    }
+
+    for (commitment, blob_hash) in blob_commitments.iter().zip(blob_hashes.iter()) {
+        if kzg_commitment_to_versioned_hash(commitment) != *blob_hash {
+            return Err(ProviderError::BlobVersionedHashMismatch);
+        }
+    }
+
+    let kzg_blobs = blobs
+        .iter()
+        .map(|blob| KzgBlob::from_bytes(blob))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|err| ProviderError::InvalidBlob(err.to_string()))?;
+
+    let kzg_commitments = blob_commitments
+        .iter()
+        .map(|commitment| KzgBytes48::from_bytes(commitment))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|err| ProviderError::InvalidKzgCommitment(err.to_string()))?;
+
+    let kzg_proofs = blob_proofs
+        .iter()
+        .map(|proof| KzgBytes48::from_bytes(proof))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|err| ProviderError::InvalidKzgProof(err.to_string()))?;
+
+    let proofs_are_valid =
+        KzgProof::verify_blob_kzg_proof_batch(&kzg_blobs, &kzg_commitments, &kzg_proofs, kzg_settings)
+            .map_err(|err| ProviderError::BlobKzgProofVerificationFailed(err.to_string()))?;
+
+    if !proofs_are_valid {
+        return Err(ProviderError::InvalidBlobKzgProof);
+    }
+
+    Ok(())
+}
+
+/// The prefix that marks an account's code as an
+/// [EIP-7702](https://eips.ethereum.org/EIPS/eip-7702) delegation
+/// designator, i.e. `0xef0100 ++ address`.
+const EIP7702_DELEGATION_DESIGNATOR_PREFIX: [u8; 3] = [0xef, 0x01, 0x00];
+
+/// The length in bytes of an EIP-7702 delegation designator.
+const EIP7702_DELEGATION_DESIGNATOR_LEN: usize = 23;
+
+/// Returns whether `code` is an EIP-7702 delegation designator rather than
+/// genuine contract code.
+fn is_eip7702_delegation_designator(code: &[u8]) -> bool {
+    code.len() == EIP7702_DELEGATION_DESIGNATOR_LEN
+        && code[..EIP7702_DELEGATION_DESIGNATOR_PREFIX.len()] == EIP7702_DELEGATION_DESIGNATOR_PREFIX
+}
+
+/// Validates that the sender of a transaction or call is allowed to
+/// originate it, per [EIP-3607](https://eips.ethereum.org/EIPS/eip-3607):
+/// accounts with deployed code cannot send transactions.
+///
+/// Impersonated accounts (`hardhat_impersonateAccount`) are exempt, since
+/// impersonating a contract account such as a multisig is a common
+/// Hardhat Network workflow. Accounts whose code is an EIP-7702 delegation
+/// designator are also exempt, since they still behave as EOAs.
+pub fn validate_sender_has_no_code<LoggerErrorT: Debug>(
+    spec_id: SpecId,
+    state: &dyn SyncState<StateError>,
+    from: &Address,
+    impersonated_accounts: &HashSet<Address>,
+) -> Result<(), ProviderError<LoggerErrorT>> {
+    if spec_id < SpecId::BERLIN || impersonated_accounts.contains(from) {
+        return Ok(());
+    }
+
+    if let Some(account_info) = state
+        .basic(*from)
+        .map_err(|err| ProviderError::State(Box::new(err)))?
+    {
+        if account_info.code_hash != KECCAK_EMPTY {
+            let code = state
+                .code_by_hash(account_info.code_hash)
+                .map_err(|err| ProviderError::State(Box::new(err)))?;
+
+            if !is_eip7702_delegation_designator(code.bytes()) {
+                return Err(ProviderError::SenderWithDeployedCode { sender: *from });
+            }
+        }
+    }
+
+    Ok(())
 }
 
 pub fn validate_eip3860_max_initcode_size<LoggerErrorT: Debug>(
@@ -428,4 +869,548 @@ mod tests {
             Err(ProviderError::InvalidTransactionInput(_))
         ));
     }
+
+    #[test]
+    fn calculate_next_base_fee_stays_the_same_at_target_gas_used() {
+        let base_fee = calculate_next_base_fee(10_000_000, 20_000_000, U256::from(1_000_000_000u64));
+
+        assert_eq!(base_fee, U256::from(1_000_000_000u64));
+    }
+
+    #[test]
+    fn calculate_next_base_fee_increases_above_target_gas_used() {
+        let base_fee = calculate_next_base_fee(20_000_000, 20_000_000, U256::from(1_000_000_000u64));
+
+        assert_eq!(base_fee, U256::from(1_125_000_000u64));
+    }
+
+    #[test]
+    fn calculate_next_base_fee_decreases_below_target_gas_used() {
+        let base_fee = calculate_next_base_fee(0, 20_000_000, U256::from(1_000_000_000u64));
+
+        assert_eq!(base_fee, U256::from(875_000_000u64));
+    }
+
+    #[test]
+    fn validate_max_fee_per_gas_rejects_underpriced_transaction() {
+        assert!(matches!(
+            validate_max_fee_per_gas::<()>(
+                SpecId::LONDON,
+                U256::from(100u64),
+                U256::from(200u64),
+                false
+            ),
+            Err(ProviderError::MaxFeePerGasTooLow { .. })
+        ));
+    }
+
+    #[test]
+    fn validate_max_fee_per_gas_accepts_sufficiently_priced_transaction() {
+        assert!(validate_max_fee_per_gas::<()>(
+            SpecId::LONDON,
+            U256::from(200u64),
+            U256::from(200u64),
+            false
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn validate_max_fee_per_gas_allows_unpriced_transaction_when_opted_in() {
+        assert!(validate_max_fee_per_gas::<()>(
+            SpecId::LONDON,
+            U256::from(100u64),
+            U256::from(200u64),
+            true
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn effective_gas_price_returns_gas_price_for_legacy_transaction() {
+        let price = effective_gas_price(
+            Some(U256::from(150u64)),
+            None,
+            None,
+            Some(U256::from(100u64)),
+        );
+
+        assert_eq!(price, U256::from(150u64));
+    }
+
+    #[test]
+    fn effective_gas_price_caps_eip1559_transaction_at_max_fee_per_gas() {
+        let price = effective_gas_price(
+            None,
+            Some(U256::from(120u64)),
+            Some(U256::from(50u64)),
+            Some(U256::from(100u64)),
+        );
+
+        // base fee (100) + priority fee (50) = 150, capped at maxFeePerGas (120)
+        assert_eq!(price, U256::from(120u64));
+    }
+
+    #[test]
+    fn effective_gas_price_pays_base_fee_plus_priority_fee_when_under_max_fee_per_gas() {
+        let price = effective_gas_price(
+            None,
+            Some(U256::from(200u64)),
+            Some(U256::from(10u64)),
+            Some(U256::from(100u64)),
+        );
+
+        assert_eq!(price, U256::from(110u64));
+    }
+
+    #[test]
+    fn effective_gas_price_returns_max_fee_per_gas_when_block_base_fee_is_unknown() {
+        let price = effective_gas_price(None, Some(U256::from(200u64)), Some(U256::from(10u64)), None);
+
+        // The next block's base fee isn't known yet: maxFeePerGas is the safe
+        // upper bound, not `0 + maxPriorityFeePerGas`.
+        assert_eq!(price, U256::from(200u64));
+    }
+
+    #[test]
+    fn kzg_commitment_to_versioned_hash_sets_the_kzg_version_byte() {
+        let versioned_hash = kzg_commitment_to_versioned_hash(&[0u8; 48]);
+
+        assert_eq!(versioned_hash.as_bytes()[0], VERSIONED_HASH_VERSION_KZG);
+    }
+
+    #[test]
+    fn calculate_blob_gas_price_is_minimum_at_zero_excess_blob_gas() {
+        assert_eq!(calculate_blob_gas_price(0), U256::from(MIN_BLOB_GASPRICE));
+    }
+
+    #[test]
+    fn calculate_blob_gas_price_increases_with_excess_blob_gas() {
+        // 1 blob of excess still rounds down to the 1 wei minimum; 100 blobs
+        // of excess gives about 50 wei.
+        let low = calculate_blob_gas_price(GAS_PER_BLOB);
+        let high = calculate_blob_gas_price(100 * GAS_PER_BLOB);
+
+        assert!(high > low);
+    }
+
+    /// Loads the real Ethereum mainnet KZG trusted setup bundled with the
+    /// workspace, so blob sidecar validation tests exercise genuine
+    /// commitment/proof verification rather than parsing alone.
+    fn test_kzg_settings() -> &'static KzgSettings {
+        use std::sync::OnceLock;
+
+        static SETTINGS: OnceLock<KzgSettings> = OnceLock::new();
+        SETTINGS.get_or_init(|| {
+            KzgSettings::load_trusted_setup_file(std::path::Path::new(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/testdata/kzg_trusted_setup.txt"
+            )))
+            .expect("failed to load the bundled KZG trusted setup")
+        })
+    }
+
+    /// Builds a valid blob sidecar (blob, KZG commitment, KZG proof and
+    /// versioned hash) for an all-zero blob.
+    fn valid_blob_sidecar() -> (Bytes, Bytes, Bytes, B256) {
+        let settings = test_kzg_settings();
+
+        let blob = KzgBlob::new([0u8; c_kzg::BYTES_PER_BLOB]);
+        let commitment = c_kzg::KzgCommitment::blob_to_kzg_commitment(&blob, settings)
+            .expect("failed to compute commitment");
+        let proof = KzgProof::compute_blob_kzg_proof(&blob, &commitment.to_bytes(), settings)
+            .expect("failed to compute proof");
+        let versioned_hash = kzg_commitment_to_versioned_hash(commitment.to_bytes().as_slice());
+
+        (
+            Bytes::from(blob.to_bytes().to_vec()),
+            Bytes::from(commitment.to_bytes().to_vec()),
+            Bytes::from(proof.to_bytes().to_vec()),
+            versioned_hash,
+        )
+    }
+
+    #[test]
+    fn validate_blob_transaction_requires_to() {
+        let (blob, commitment, proof, versioned_hash) = valid_blob_sidecar();
+        let settings = test_kzg_settings();
+
+        assert!(matches!(
+            validate_blob_transaction::<()>(
+                SpecId::CANCUN,
+                None,
+                &[blob],
+                &[versioned_hash],
+                &[commitment],
+                &[proof],
+                Some(&U256::from(1u64)),
+                None,
+                settings,
+            ),
+            Err(ProviderError::BlobTransactionMissingTo)
+        ));
+    }
+
+    #[test]
+    fn validate_blob_transaction_rejects_empty_blobs() {
+        let settings = test_kzg_settings();
+
+        assert!(matches!(
+            validate_blob_transaction::<()>(
+                SpecId::CANCUN,
+                Some(&Address::ZERO),
+                &[],
+                &[],
+                &[],
+                &[],
+                Some(&U256::from(1u64)),
+                None,
+                settings,
+            ),
+            Err(ProviderError::EmptyBlobs)
+        ));
+    }
+
+    #[test]
+    fn validate_blob_transaction_rejects_too_many_blobs() {
+        let (blob, commitment, proof, versioned_hash) = valid_blob_sidecar();
+        let settings = test_kzg_settings();
+
+        let blobs = vec![blob; MAX_BLOBS_PER_TRANSACTION + 1];
+        let commitments = vec![commitment; MAX_BLOBS_PER_TRANSACTION + 1];
+        let proofs = vec![proof; MAX_BLOBS_PER_TRANSACTION + 1];
+        let versioned_hashes = vec![versioned_hash; MAX_BLOBS_PER_TRANSACTION + 1];
+
+        assert!(matches!(
+            validate_blob_transaction::<()>(
+                SpecId::CANCUN,
+                Some(&Address::ZERO),
+                &blobs,
+                &versioned_hashes,
+                &commitments,
+                &proofs,
+                Some(&U256::from(1u64)),
+                None,
+                settings,
+            ),
+            Err(ProviderError::TooManyBlobs { .. })
+        ));
+    }
+
+    #[test]
+    fn validate_blob_transaction_rejects_mismatched_sidecar_lengths() {
+        let (blob, commitment, proof, versioned_hash) = valid_blob_sidecar();
+        let settings = test_kzg_settings();
+
+        assert!(matches!(
+            validate_blob_transaction::<()>(
+                SpecId::CANCUN,
+                Some(&Address::ZERO),
+                &[blob],
+                &[versioned_hash],
+                &[commitment],
+                &[proof, Bytes::from_static(&[0u8; 48])],
+                Some(&U256::from(1u64)),
+                None,
+                settings,
+            ),
+            Err(ProviderError::BlobSidecarLengthMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn validate_blob_transaction_rejects_versioned_hash_mismatch() {
+        let (blob, commitment, proof, _versioned_hash) = valid_blob_sidecar();
+        let settings = test_kzg_settings();
+
+        assert!(matches!(
+            validate_blob_transaction::<()>(
+                SpecId::CANCUN,
+                Some(&Address::ZERO),
+                &[blob],
+                &[B256::repeat_byte(0xff)],
+                &[commitment],
+                &[proof],
+                Some(&U256::from(1u64)),
+                None,
+                settings,
+            ),
+            Err(ProviderError::BlobVersionedHashMismatch)
+        ));
+    }
+
+    #[test]
+    fn validate_blob_transaction_accepts_valid_sidecar() {
+        let (blob, commitment, proof, versioned_hash) = valid_blob_sidecar();
+        let settings = test_kzg_settings();
+
+        assert!(validate_blob_transaction::<()>(
+            SpecId::CANCUN,
+            Some(&Address::ZERO),
+            &[blob],
+            &[versioned_hash],
+            &[commitment],
+            &[proof],
+            Some(&U256::from(1u64)),
+            None,
+            settings,
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn validate_blob_transaction_rejects_tampered_proof() {
+        let (blob, commitment, proof, versioned_hash) = valid_blob_sidecar();
+        let settings = test_kzg_settings();
+
+        // Flipping the first byte clears the compressed-point flag, so the
+        // KZG backend rejects the proof's encoding before verifying it.
+        let mut tampered_proof = proof.to_vec();
+        tampered_proof[0] ^= 0xff;
+
+        assert!(matches!(
+            validate_blob_transaction::<()>(
+                SpecId::CANCUN,
+                Some(&Address::ZERO),
+                &[blob],
+                &[versioned_hash],
+                &[commitment],
+                &[Bytes::from(tampered_proof)],
+                Some(&U256::from(1u64)),
+                None,
+                settings,
+            ),
+            Err(ProviderError::BlobKzgProofVerificationFailed(_))
+        ));
+    }
+
+    #[test]
+    fn validate_blob_transaction_rejects_low_max_fee_per_blob_gas() {
+        let (blob, commitment, proof, versioned_hash) = valid_blob_sidecar();
+        let settings = test_kzg_settings();
+
+        // A large excess blob gas drives the blob base fee above the
+        // transaction's maxFeePerBlobGas of 1 wei.
+        assert!(matches!(
+            validate_blob_transaction::<()>(
+                SpecId::CANCUN,
+                Some(&Address::ZERO),
+                &[blob],
+                &[versioned_hash],
+                &[commitment],
+                &[proof],
+                Some(&U256::from(1u64)),
+                Some(100 * GAS_PER_BLOB),
+                settings,
+            ),
+            Err(ProviderError::MaxFeePerBlobGasTooLow { .. })
+        ));
+    }
+
+    #[derive(Debug, Default)]
+    struct MockState {
+        code_hash: B256,
+        code: Bytes,
+    }
+
+    impl edr_evm::state::State for MockState {
+        type Error = StateError;
+
+        fn basic(
+            &self,
+            _address: Address,
+        ) -> Result<Option<edr_eth::account::AccountInfo>, Self::Error> {
+            Ok(Some(edr_eth::account::AccountInfo {
+                code_hash: self.code_hash,
+                ..edr_eth::account::AccountInfo::default()
+            }))
+        }
+
+        fn code_by_hash(&self, _code_hash: B256) -> Result<edr_evm::Bytecode, Self::Error> {
+            Ok(edr_evm::Bytecode::new_raw(self.code.clone()))
+        }
+
+        fn storage(&self, _address: Address, _index: U256) -> Result<U256, Self::Error> {
+            unreachable!("not used by validate_sender_has_no_code")
+        }
+
+        fn block_hash(&self, _number: U256) -> Result<B256, Self::Error> {
+            unreachable!("not used by validate_sender_has_no_code")
+        }
+    }
+
+    #[test]
+    fn validate_sender_has_no_code_allows_eoa() {
+        let state = MockState {
+            code_hash: KECCAK_EMPTY,
+            ..MockState::default()
+        };
+
+        assert!(validate_sender_has_no_code::<()>(
+            SpecId::LONDON,
+            &state,
+            &Address::ZERO,
+            &HashSet::new()
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn validate_sender_has_no_code_rejects_contract() {
+        let state = MockState {
+            code_hash: B256::repeat_byte(1),
+            code: Bytes::from_static(&[0x60, 0x00]),
+        };
+
+        assert!(matches!(
+            validate_sender_has_no_code::<()>(
+                SpecId::LONDON,
+                &state,
+                &Address::ZERO,
+                &HashSet::new()
+            ),
+            Err(ProviderError::SenderWithDeployedCode { .. })
+        ));
+    }
+
+    #[test]
+    fn validate_sender_has_no_code_skips_pre_berlin() {
+        let state = MockState {
+            code_hash: B256::repeat_byte(1),
+            code: Bytes::from_static(&[0x60, 0x00]),
+        };
+
+        assert!(validate_sender_has_no_code::<()>(
+            SpecId::MUIR_GLACIER,
+            &state,
+            &Address::ZERO,
+            &HashSet::new()
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn validate_sender_has_no_code_skips_impersonated_account() {
+        let state = MockState {
+            code_hash: B256::repeat_byte(1),
+            code: Bytes::from_static(&[0x60, 0x00]),
+        };
+        let impersonated_accounts = HashSet::from([Address::ZERO]);
+
+        assert!(validate_sender_has_no_code::<()>(
+            SpecId::LONDON,
+            &state,
+            &Address::ZERO,
+            &impersonated_accounts
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn validate_sender_has_no_code_skips_eip7702_delegation_designator() {
+        let mut code = EIP7702_DELEGATION_DESIGNATOR_PREFIX.to_vec();
+        code.extend_from_slice(Address::ZERO.as_slice());
+
+        let state = MockState {
+            code_hash: B256::repeat_byte(1),
+            code: Bytes::from(code),
+        };
+
+        assert!(validate_sender_has_no_code::<()>(
+            SpecId::LONDON,
+            &state,
+            &Address::ZERO,
+            &HashSet::new()
+        )
+        .is_ok());
+    }
+
+    /// Exercises `validate_transaction_and_call_request` as a whole (rather
+    /// than `validate_blob_transaction` directly) to prove that a blob
+    /// sidecar is actually checked along the composed validation path, not
+    /// just when called in isolation.
+    #[test]
+    fn validate_transaction_and_call_request_rejects_invalid_blob_sidecar() {
+        let (blob, commitment, proof, _versioned_hash) = valid_blob_sidecar();
+        let settings = test_kzg_settings();
+
+        let request = EthTransactionRequest {
+            from: Address::ZERO,
+            to: Some(Address::ZERO),
+            blobs: Some(vec![blob]),
+            // A versioned hash that does not match the supplied commitment.
+            blob_hashes: Some(vec![B256::repeat_byte(0xff)]),
+            max_fee_per_blob_gas: Some(U256::from(1u64)),
+            blob_commitments: Some(vec![commitment]),
+            blob_proofs: Some(vec![proof]),
+            ..EthTransactionRequest::default()
+        };
+
+        let state = MockState {
+            code_hash: KECCAK_EMPTY,
+            ..MockState::default()
+        };
+        let impersonated_accounts = HashSet::new();
+
+        let context = TransactionValidationContext {
+            state: &state,
+            impersonated_accounts: &impersonated_accounts,
+            next_block_base_fee: None,
+            allow_unpriced_transactions: true,
+            parent_excess_blob_gas: None,
+            kzg_settings: settings,
+        };
+
+        assert!(matches!(
+            validate_transaction_and_call_request::<()>(
+                SpecId::CANCUN,
+                &request,
+                request.from,
+                request.to.as_ref(),
+                context,
+            ),
+            Err(ProviderError::BlobVersionedHashMismatch)
+        ));
+    }
+
+    #[test]
+    fn validate_transaction_and_call_request_accepts_valid_blob_sidecar() {
+        let (blob, commitment, proof, versioned_hash) = valid_blob_sidecar();
+        let settings = test_kzg_settings();
+
+        let request = EthTransactionRequest {
+            from: Address::ZERO,
+            to: Some(Address::ZERO),
+            blobs: Some(vec![blob]),
+            blob_hashes: Some(vec![versioned_hash]),
+            max_fee_per_blob_gas: Some(U256::from(1u64)),
+            blob_commitments: Some(vec![commitment]),
+            blob_proofs: Some(vec![proof]),
+            ..EthTransactionRequest::default()
+        };
+
+        let state = MockState {
+            code_hash: KECCAK_EMPTY,
+            ..MockState::default()
+        };
+        let impersonated_accounts = HashSet::new();
+
+        let context = TransactionValidationContext {
+            state: &state,
+            impersonated_accounts: &impersonated_accounts,
+            next_block_base_fee: None,
+            allow_unpriced_transactions: true,
+            parent_excess_blob_gas: None,
+            kzg_settings: settings,
+        };
+
+        assert!(validate_transaction_and_call_request::<()>(
+            SpecId::CANCUN,
+            &request,
+            request.from,
+            request.to.as_ref(),
+            context,
+        )
+        .is_ok());
+    }
 }